@@ -1,9 +1,14 @@
 mod field;
 
+use crate::datamodel::graphql::write_graphql_description;
+use crate::datamodel::RenameRule;
 use crate::value::{Constant, Documentation};
 pub use field::CompositeTypeField;
 use psl::dml;
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write as _},
+};
 
 /// A type block in a PSL file.
 #[derive(Debug)]
@@ -68,23 +73,206 @@ impl<'a> CompositeType<'a> {
 
         composite_type
     }
-}
 
-impl<'a> fmt::Display for CompositeType<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(ref docs) = self.documentation {
-            docs.fmt(f)?;
+    /// Renders the type as a GraphQL SDL `type` definition, mapping every
+    /// field's PSL type to its GraphQL equivalent.
+    ///
+    /// ```ignore
+    /// """
+    /// ...so many tears
+    /// """
+    /// type Address {
+    ///   street: String!
+    ///   city: String
+    /// }
+    /// ```
+    pub fn to_graphql_sdl(&self, options: &SdlExportOptions) -> String {
+        let mut out = String::new();
+
+        if options.shows_descriptions() {
+            if let Some(ref docs) = self.documentation {
+                write_graphql_description(&mut out, docs);
+            }
         }
 
-        writeln!(f, "type {} {{", self.name)?;
+        writeln!(out, "type {} {{", self.name).unwrap();
+
+        let mut fields: Vec<(Option<String>, String)> = self
+            .fields
+            .iter()
+            .map(|field| field.to_graphql_sdl(options))
+            .filter(|rendered| !rendered.is_empty())
+            .map(|rendered| {
+                let name = rendered
+                    .lines()
+                    .last()
+                    .and_then(|line| line.split(':').next().map(|name| name.trim().to_owned()));
+
+                let block = rendered
+                    .trim_end()
+                    .lines()
+                    .map(|line| format!("  {line}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
 
-        for field in self.fields.iter() {
-            writeln!(f, "{field}")?;
+                (name, block)
+            })
+            .collect();
+
+        if options.is_sorted() {
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        for (_, field) in fields {
+            writeln!(out, "{field}").unwrap();
         }
 
-        f.write_str("}\n")?;
+        out.push_str("}\n");
+        out
+    }
+
+    /// Converts every field's name to `rule`'s naming convention, filling in
+    /// `map(...)` for any field whose database name then differs from its
+    /// logical name. Fields already conforming to the convention are left
+    /// untouched.
+    pub fn apply_rename_rule(&mut self, rule: RenameRule) {
+        for field in self.fields.iter_mut() {
+            field.apply_rename_rule(rule);
+        }
+    }
+
+    /// Renders the type the same way as [`fmt::Display`], but honoring
+    /// `options`: fields can be sorted alphabetically, their own attributes
+    /// can be sorted too, `documentation` can be suppressed, and
+    /// commented-out fields can be stripped entirely instead of rendered
+    /// as PSL comments. Useful for producing a normalized, diffable form
+    /// regardless of the order fields and attributes were pushed in.
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut out = String::new();
+
+        if options.shows_documentation() {
+            if let Some(ref docs) = self.documentation {
+                out.push_str(&docs.to_string());
+            }
+        }
+
+        writeln!(out, "type {} {{", self.name).unwrap();
+
+        let mut lines: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| field.render_with(options))
+            .filter(|rendered| !rendered.is_empty())
+            .collect();
+
+        if options.sorts_fields() {
+            lines.sort_by(|a, b| {
+                let key = |line: &str| {
+                    line.lines().last().map(|line| {
+                        let line = line.trim_start().trim_start_matches("// ");
+                        line.split_whitespace().next().unwrap_or_default().to_owned()
+                    })
+                };
+                key(a).cmp(&key(b))
+            });
+        }
+
+        for line in lines {
+            writeln!(out, "{line}").unwrap();
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Options controlling [`CompositeType::to_graphql_sdl`] and
+/// [`ModelField::to_graphql_sdl`](crate::datamodel::ModelField::to_graphql_sdl), mirroring the
+/// `SDLExportOptions` pattern used by async-graphql's registry export.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SdlExportOptions {
+    sort: bool,
+    hide_descriptions: bool,
+}
+
+impl SdlExportOptions {
+    /// Sorts a type's fields alphabetically by name, so the rendered SDL is
+    /// stable no matter the order fields were pushed in. Each type is
+    /// rendered on its own, so the order of types is not affected.
+    pub fn sort(&mut self) {
+        self.sort = true;
+    }
+
+    /// Omits `documentation` blocks from the rendered SDL.
+    pub fn hide_descriptions(&mut self) {
+        self.hide_descriptions = true;
+    }
+
+    pub(crate) fn is_sorted(&self) -> bool {
+        self.sort
+    }
 
-        Ok(())
+    pub(crate) fn shows_descriptions(&self) -> bool {
+        !self.hide_descriptions
+    }
+}
+
+/// Options controlling [`CompositeType::render_with`] and
+/// [`ModelField::render_with`](crate::datamodel::ModelField::render_with), analogous to
+/// async-graphql's `SDLExportOptions`. Lets consumers produce a normalized, reproducible
+/// rendering of a type regardless of the order fields and attributes were pushed in -
+/// handy for diffing introspection output across runs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    sort_fields: bool,
+    sort_attributes: bool,
+    hide_documentation: bool,
+    strip_commented_out: bool,
+}
+
+impl RenderOptions {
+    /// Sorts fields alphabetically by name.
+    pub fn sort_fields(&mut self) {
+        self.sort_fields = true;
+    }
+
+    /// Sorts each field's own attributes alphabetically by attribute name,
+    /// with a native type attribute (`@db.*`) sorted as `nativeType`.
+    pub fn sort_attributes(&mut self) {
+        self.sort_attributes = true;
+    }
+
+    /// Omits `documentation` blocks from the rendered output.
+    pub fn hide_documentation(&mut self) {
+        self.hide_documentation = true;
+    }
+
+    /// Drops commented-out fields entirely, instead of rendering them as
+    /// PSL comments.
+    pub fn strip_commented_out(&mut self) {
+        self.strip_commented_out = true;
+    }
+
+    pub(crate) fn sorts_fields(&self) -> bool {
+        self.sort_fields
+    }
+
+    pub(crate) fn sorts_attributes(&self) -> bool {
+        self.sort_attributes
+    }
+
+    pub(crate) fn shows_documentation(&self) -> bool {
+        !self.hide_documentation
+    }
+
+    pub(crate) fn strips_commented_out(&self) -> bool {
+        self.strip_commented_out
+    }
+}
+
+impl<'a> fmt::Display for CompositeType<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(&RenderOptions::default()))
     }
 }
 
@@ -142,4 +330,92 @@ mod tests {
         let rendered = psl::reformat(&format!("{composite_type}"), 2).unwrap();
         expected.assert_eq(&rendered);
     }
+
+    #[test]
+    fn to_graphql_sdl_delegates_to_fields() {
+        let mut composite_type = CompositeType::new("Address");
+
+        let mut field = CompositeTypeField::new("street2", "String");
+        field.optional();
+        field.documentation("The second address line.");
+        field.deprecated(Some("use `street` instead"));
+        composite_type.push_field(field);
+
+        let mut field = CompositeTypeField::new("number", "Int");
+        field.commented_out();
+        composite_type.push_field(field);
+
+        let expected = expect![[r#"
+            type Address {
+              """
+              The second address line.
+              """
+              street2: String @deprecated(reason: "use `street` instead")
+            }
+        "#]];
+
+        expected.assert_eq(&composite_type.to_graphql_sdl(&SdlExportOptions::default()));
+    }
+
+    #[test]
+    fn apply_rename_rule_maps_non_conforming_field_names() {
+        let mut composite_type = CompositeType::new("Address");
+        composite_type.push_field(CompositeTypeField::new("createdAt", "DateTime"));
+        composite_type.apply_rename_rule(RenameRule::SnakeCase);
+
+        let expected = expect![[r#"
+            type Address {
+              createdAt DateTime @map("created_at")
+            }
+        "#]];
+
+        let rendered = psl::reformat(&format!("{composite_type}"), 2).unwrap();
+        expected.assert_eq(&rendered);
+    }
+
+    #[test]
+    fn apply_rename_rule_keeps_existing_field_map() {
+        let mut composite_type = CompositeType::new("Address");
+
+        let mut field = CompositeTypeField::new("createdAt", "DateTime");
+        field.map("creation_date");
+        composite_type.push_field(field);
+
+        composite_type.apply_rename_rule(RenameRule::SnakeCase);
+
+        let expected = expect![[r#"
+            type Address {
+              createdAt DateTime @map("creation_date")
+            }
+        "#]];
+
+        let rendered = psl::reformat(&format!("{composite_type}"), 2).unwrap();
+        expected.assert_eq(&rendered);
+    }
+
+    #[test]
+    fn render_with_sorts_fields_and_attributes() {
+        let mut composite_type = CompositeType::new("Address");
+
+        let mut field = CompositeTypeField::new("street", "String");
+        field.map("Straße");
+        field.native_type("db", "VarChar", vec!["255".into()]);
+        composite_type.push_field(field);
+
+        let mut field = CompositeTypeField::new("number", "Int");
+        composite_type.push_field(field);
+
+        let mut options = RenderOptions::default();
+        options.sort_fields();
+        options.sort_attributes();
+
+        let expected = expect![[r#"
+            type Address {
+            number Int
+            street String @map("Straße") @db.VarChar(255)
+            }
+        "#]];
+
+        expected.assert_eq(&composite_type.render_with(&options));
+    }
 }
@@ -0,0 +1,350 @@
+use crate::{
+    datamodel::{
+        attributes::FieldAttribute,
+        deprecated::{parse_deprecated_marker, Deprecated},
+        graphql::{graphql_string_escape, graphql_type, rendered_field_parts, write_graphql_description},
+        DefaultValue, FieldType, RenameRule, RenderOptions, SdlExportOptions,
+    },
+    value::{Constant, Documentation, Function},
+};
+use psl::dml;
+use std::{
+    borrow::Cow,
+    fmt::{self, Write as _},
+};
+
+/// A field in a composite type block.
+#[derive(Debug)]
+pub struct CompositeTypeField<'a> {
+    name: Constant<Cow<'a, str>>,
+    commented_out: bool,
+    r#type: FieldType<'a>,
+    documentation: Option<Documentation<'a>>,
+    default: Option<DefaultValue<'a>>,
+    map: Option<FieldAttribute<'a>>,
+    native_type: Option<FieldAttribute<'a>>,
+    deprecated: Option<Deprecated<'a>>,
+}
+
+impl<'a> CompositeTypeField<'a> {
+    /// Create a new required composite type field declaration.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String
+    /// //       ^^^^^^ type_name
+    /// //^^^^^^ name
+    /// }
+    /// ```
+    pub fn new(name: impl Into<Cow<'a, str>>, type_name: impl Into<Cow<'a, str>>) -> Self {
+        let name = Constant::new_no_validate(name.into());
+
+        Self {
+            name,
+            commented_out: false,
+            r#type: FieldType::required(type_name),
+            documentation: None,
+            default: None,
+            map: None,
+            native_type: None,
+            deprecated: None,
+        }
+    }
+
+    /// Sets the field as optional.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String?
+    /// //             ^ this
+    /// }
+    /// ```
+    pub fn optional(&mut self) {
+        self.r#type.into_optional();
+    }
+
+    /// Sets the field to be an array.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String[]
+    /// //             ^^ this
+    /// }
+    /// ```
+    pub fn array(&mut self) {
+        self.r#type.into_array();
+    }
+
+    /// Sets the field map attribute.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String @map("Straße")
+    ///                       ^^^^^^ value
+    /// }
+    /// ```
+    pub fn map(&mut self, value: impl Into<Cow<'a, str>>) {
+        let mut map = Function::new("map");
+        map.push_param(value.into());
+
+        self.map = Some(FieldAttribute::new(map));
+    }
+
+    /// Documentation of the field.
+    ///
+    /// ```ignore
+    /// type Foo {
+    ///   /// This is the documentation.
+    ///   bar Int
+    /// }
+    /// ```
+    pub fn documentation(&mut self, documentation: impl Into<Cow<'a, str>>) {
+        match self.documentation.as_mut() {
+            Some(docs) => docs.push(documentation),
+            None => self.documentation = Some(Documentation(documentation.into())),
+        }
+    }
+
+    /// Sets the field default attribute.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String @default("Prenzlauer Allee")
+    ///                           ^^^^^^^^^^^^^^^^ value
+    /// }
+    /// ```
+    pub fn default(&mut self, value: DefaultValue<'a>) {
+        self.default = Some(value);
+    }
+
+    /// Sets the native type of the field.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   street String @db.VarChar(255)
+    /// //                          ^^^ param
+    /// //                  ^^^^^^^ type_name
+    /// //               ^^ prefix
+    /// }
+    /// ```
+    ///
+    /// TODO: `params` as `&[&str]` when we get rid of the DML.
+    pub fn native_type(
+        &mut self,
+        prefix: impl Into<Cow<'a, str>>,
+        r#type: impl Into<Cow<'a, str>>,
+        params: Vec<String>,
+    ) {
+        let mut native_type = FieldAttribute::new(Function::new(r#type));
+
+        for param in params {
+            native_type.push_param(Constant::new_no_validate(param));
+        }
+
+        native_type.prefix(prefix);
+
+        self.native_type = Some(native_type);
+    }
+
+    /// Comments the field out.
+    pub fn commented_out(&mut self) {
+        self.commented_out = true;
+    }
+
+    /// Marks the field as deprecated, with an optional reason. Renders as a
+    /// `@deprecated` tag on its own documentation line, ahead of any
+    /// `documentation` already set on the field.
+    ///
+    /// ```ignore
+    /// type Address {
+    ///   /// @deprecated use `street2` instead
+    ///   street String
+    /// }
+    /// ```
+    pub fn deprecated(&mut self, reason: Option<impl Into<Cow<'a, str>>>) {
+        self.deprecated = Some(Deprecated::new(reason));
+    }
+
+    /// Sets `documentation` from a DML doc string, pulling a leading
+    /// `@deprecated` marker (if any) out into [`deprecated`](Self::deprecated)
+    /// instead of leaving it as plain text, so a schema rendered by this
+    /// crate and re-parsed back into DML round-trips through the same
+    /// structured deprecation.
+    fn apply_documentation(&mut self, docs: &str) {
+        let (deprecated, remainder) = parse_deprecated_marker(docs);
+
+        if let Some(deprecated) = deprecated {
+            self.deprecated = Some(deprecated);
+        }
+
+        if !remainder.is_empty() {
+            self.documentation(remainder);
+        }
+    }
+
+    /// Renders this field as a line of GraphQL SDL, mapping the PSL type to
+    /// the matching GraphQL scalar (or keeping enum and composite type names
+    /// as-is) and turning `documentation` into the field's description.
+    /// Commented-out fields render as an empty string, since they have no
+    /// place in a GraphQL schema.
+    ///
+    /// ```ignore
+    /// """
+    /// The street name.
+    /// """
+    /// street: String!
+    /// ```
+    pub fn to_graphql_sdl(&self, options: &SdlExportOptions) -> String {
+        let mut out = String::new();
+
+        if self.commented_out {
+            return out;
+        }
+
+        if options.shows_descriptions() {
+            if let Some(ref docs) = self.documentation {
+                write_graphql_description(&mut out, docs);
+            }
+        }
+
+        let rendered = self.to_string();
+
+        if let Some((name, type_token)) = rendered_field_parts(&rendered) {
+            write!(out, "{name}: {}", graphql_type(type_token)).unwrap();
+
+            if let Some(ref dep) = self.deprecated {
+                match dep.reason() {
+                    Some(reason) => write!(out, " @deprecated(reason: \"{}\")", graphql_string_escape(reason)).unwrap(),
+                    None => out.push_str(" @deprecated"),
+                }
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Converts this field's name to `rule`'s naming convention, filling in
+    /// `map(...)` when the converted name differs from the original. Does
+    /// nothing if the field's name already conforms to the convention, or if
+    /// it's already mapped to a real column name, which may have nothing to
+    /// do with the naming convention.
+    pub fn apply_rename_rule(&mut self, rule: RenameRule) {
+        if self.map.is_some() {
+            return;
+        }
+
+        let original = self.name.to_string();
+        let mapped = rule.apply(&original);
+
+        if mapped != original {
+            self.map(mapped);
+        }
+    }
+
+    /// Renders this field the same way as [`fmt::Display`], but honoring
+    /// `options`: attributes can be sorted alphabetically, `documentation`
+    /// can be suppressed, and the field can be dropped entirely instead of
+    /// rendered as a PSL comment when it's commented out. Useful for
+    /// producing a normalized, diffable form regardless of the order
+    /// attributes were set in.
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut out = String::new();
+
+        if self.commented_out && options.strips_commented_out() {
+            return out;
+        }
+
+        if let Some(ref dep) = self.deprecated {
+            out.push_str(&dep.render_doc_lines());
+        }
+
+        if options.shows_documentation() {
+            if let Some(ref docs) = self.documentation {
+                out.push_str(&docs.to_string());
+            }
+        }
+
+        if self.commented_out {
+            out.push_str("// ");
+        }
+
+        write!(out, "{} {}", self.name, self.r#type).unwrap();
+
+        let mut attributes: Vec<(&str, String)> = Vec::new();
+
+        if let Some(ref def) = self.default {
+            attributes.push(("default", def.to_string()));
+        }
+
+        if let Some(ref map) = self.map {
+            attributes.push(("map", map.to_string()));
+        }
+
+        if let Some(ref nt) = self.native_type {
+            attributes.push(("nativeType", nt.to_string()));
+        }
+
+        if options.sorts_attributes() {
+            attributes.sort_by(|a, b| a.0.cmp(b.0));
+        }
+
+        for (_, rendered) in attributes {
+            write!(out, " {rendered}").unwrap();
+        }
+
+        out
+    }
+
+    /// Generate a composite type field rendering from the deprecated DML
+    /// structure.
+    ///
+    /// Remove when destroying the DML.
+    pub(super) fn from_dml(datasource: &'a psl::Datasource, dml_field: &dml::CompositeTypeField) -> Self {
+        let (r#type, native_type): (String, _) = match dml_field.r#type {
+            dml::CompositeTypeFieldType::CompositeType(ref ct) => (ct.clone(), None),
+            dml::CompositeTypeFieldType::Scalar(ref st, ref nt) => {
+                (st.as_ref().to_owned(), nt.as_ref().map(|nt| (nt.name(), nt.args())))
+            }
+            dml::CompositeTypeFieldType::Enum(ref ct) => (ct.clone(), None),
+            dml::CompositeTypeFieldType::Unsupported(ref s) => (s.clone(), None),
+        };
+
+        let mut field = Self::new(dml_field.name.clone(), r#type);
+
+        match dml_field.arity {
+            dml::FieldArity::Optional => field.optional(),
+            dml::FieldArity::List => field.array(),
+            dml::FieldArity::Required => (),
+        }
+
+        if let Some(ref docs) = dml_field.documentation {
+            field.apply_documentation(docs);
+        }
+
+        if let Some(ref dv) = dml_field.default_value {
+            field.default(DefaultValue::from_dml(dv));
+        }
+
+        if let Some((name, args)) = native_type {
+            field.native_type(&datasource.name, name, args);
+        }
+
+        if let Some(ref map) = dml_field.database_name {
+            field.map(map.clone());
+        }
+
+        if dml_field.is_commented_out {
+            field.commented_out();
+        }
+
+        field
+    }
+}
+
+impl<'a> fmt::Display for CompositeTypeField<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(&RenderOptions::default()))
+    }
+}
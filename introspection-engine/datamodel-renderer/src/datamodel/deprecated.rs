@@ -0,0 +1,70 @@
+use std::{borrow::Cow, fmt::Write as _};
+
+/// A deprecation marker, with an optional reason, rendered as the first
+/// line(s) of a field's documentation. Shared by
+/// [`ModelField`](crate::datamodel::ModelField) and
+/// [`CompositeTypeField`](crate::datamodel::CompositeTypeField), which both
+/// store one behind an `Option`.
+#[derive(Debug, Clone)]
+pub(crate) struct Deprecated<'a> {
+    reason: Option<Cow<'a, str>>,
+}
+
+impl<'a> Deprecated<'a> {
+    pub(crate) fn new(reason: Option<impl Into<Cow<'a, str>>>) -> Self {
+        Self {
+            reason: reason.map(Into::into),
+        }
+    }
+
+    pub(crate) fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Renders this marker as one or more `///` doc-comment lines. A reason
+    /// spanning several lines gets a `///` prefix on each of them, so it
+    /// can't break out of the doc comment it's part of.
+    pub(crate) fn render_doc_lines(&self) -> String {
+        let mut out = String::new();
+
+        match self.reason.as_deref() {
+            Some(reason) => {
+                let mut lines = reason.lines();
+                writeln!(out, "/// @deprecated {}", lines.next().unwrap_or_default()).unwrap();
+
+                for line in lines {
+                    writeln!(out, "/// {line}").unwrap();
+                }
+            }
+            None => writeln!(out, "/// @deprecated").unwrap(),
+        }
+
+        out
+    }
+}
+
+/// Pulls a leading `@deprecated` marker (if any) out of a DML doc string,
+/// returning the parsed marker and the remaining documentation text, so a
+/// schema rendered by this crate and re-parsed back into DML round-trips
+/// through the same structured deprecation instead of leaving it as plain
+/// text.
+pub(crate) fn parse_deprecated_marker(docs: &str) -> (Option<Deprecated<'static>>, String) {
+    let mut lines = docs.lines();
+    let first_line = lines.clone().next().map(str::trim_start);
+    let marker = first_line.filter(|line| *line == "@deprecated" || line.starts_with("@deprecated "));
+
+    let deprecated = marker.map(|line| {
+        let reason = line["@deprecated".len()..].trim();
+        lines.next();
+
+        Deprecated::new(if reason.is_empty() {
+            None
+        } else {
+            Some(reason.to_owned())
+        })
+    });
+
+    let remainder = lines.collect::<Vec<_>>().join("\n");
+
+    (deprecated, remainder)
+}
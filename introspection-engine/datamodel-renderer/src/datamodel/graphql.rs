@@ -0,0 +1,91 @@
+use crate::value::Documentation;
+use std::fmt::Write as _;
+
+/// Maps a rendered PSL type token (e.g. `String`, `String?`, `String[]`,
+/// `Unsupported("xml")`) to its GraphQL SDL equivalent. Scalars not known to
+/// GraphQL fall back to `String`; enum, relation and composite type names
+/// are kept as-is so they can reference their own SDL type.
+///
+/// Shared by [`CompositeTypeField::to_graphql_sdl`](crate::datamodel::CompositeTypeField::to_graphql_sdl) and
+/// [`ModelField::to_graphql_sdl`](crate::datamodel::ModelField::to_graphql_sdl).
+pub(crate) fn graphql_type(type_token: &str) -> String {
+    let optional = type_token.ends_with('?');
+    let base = type_token.trim_end_matches('?');
+
+    let (base, is_list) = match base.strip_suffix("[]") {
+        Some(inner) => (inner, true),
+        None => (base, false),
+    };
+
+    let scalar = if base.starts_with("Unsupported(") {
+        "String"
+    } else {
+        match base {
+            "Int" | "BigInt" => "Int",
+            "Float" | "Decimal" => "Float",
+            "Boolean" => "Boolean",
+            "String" | "DateTime" | "Json" | "Bytes" => "String",
+            other => other,
+        }
+    };
+
+    match (is_list, optional) {
+        (true, true) => format!("[{scalar}!]"),
+        (true, false) => format!("[{scalar}!]!"),
+        (false, true) => scalar.to_string(),
+        (false, false) => format!("{scalar}!"),
+    }
+}
+
+/// Pulls the `name type` pair out of a field's own rendered PSL line, so the
+/// SDL exporter can reuse the existing `fmt::Display` output instead of
+/// duplicating each field type's private state. Returns `None` for
+/// commented-out fields, which have no place in a GraphQL schema.
+pub(crate) fn rendered_field_parts(rendered: &str) -> Option<(&str, &str)> {
+    let line = rendered.lines().last()?;
+
+    if line.trim_start().starts_with("//") {
+        return None;
+    }
+
+    let mut parts = line.split_whitespace();
+    let name = parts.next()?;
+    let type_token = parts.next()?;
+
+    Some((name, type_token))
+}
+
+/// Renders a [`Documentation`] block as a GraphQL SDL triple-quoted
+/// description.
+pub(crate) fn write_graphql_description(out: &mut String, docs: &Documentation<'_>) {
+    let text = docs
+        .to_string()
+        .lines()
+        .map(|line| line.trim_start_matches("///").trim())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    writeln!(out, "\"\"\"\n{text}\n\"\"\"").unwrap();
+}
+
+/// Escapes a string for use inside a GraphQL SDL string literal, per the
+/// GraphQL spec's `StringCharacter` production (backslash and quote escaped,
+/// newline, carriage return and tab as `\n`, `\r` and `\t`, and other
+/// control characters as a four-hex-digit `\uXXXX` escape).
+pub(crate) fn graphql_string_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+
+    for ch in value.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+
+    out
+}
@@ -0,0 +1,137 @@
+mod field;
+
+use crate::datamodel::graphql::write_graphql_description;
+use crate::datamodel::SdlExportOptions;
+use crate::value::{Constant, Documentation};
+pub use field::ModelField;
+use std::{borrow::Cow, fmt::Write as _};
+
+/// A model block in a PSL file.
+#[derive(Debug)]
+pub struct Model<'a> {
+    name: Constant<Cow<'a, str>>,
+    documentation: Option<Documentation<'a>>,
+    fields: Vec<ModelField<'a>>,
+}
+
+impl<'a> Model<'a> {
+    /// Create a new model declaration block. Will not be valid without
+    /// adding at least one field.
+    ///
+    /// ```ignore
+    /// model User {
+    /// //    ^^^^ name
+    /// }
+    /// ```
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        let name = Constant::new_no_validate(name.into());
+
+        Self {
+            name,
+            documentation: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Documentation of the model.
+    ///
+    /// ```ignore
+    /// /// This is the documentation.
+    /// model User {
+    ///   ....
+    /// }
+    /// ```
+    pub fn documentation(&mut self, documentation: impl Into<Cow<'a, str>>) {
+        self.documentation = Some(Documentation(documentation.into()));
+    }
+
+    /// Add a new field to the model.
+    ///
+    /// ```ignore
+    /// model User {
+    ///     name String
+    /// //  ^^^^^^^^^^^ this
+    /// }
+    /// ```
+    pub fn push_field(&mut self, field: ModelField<'a>) {
+        self.fields.push(field);
+    }
+
+    /// Renders the model as a GraphQL SDL `type` definition, mapping every
+    /// field's PSL type to its GraphQL equivalent.
+    ///
+    /// ```ignore
+    /// """
+    /// A registered user.
+    /// """
+    /// type User {
+    ///   id: Int!
+    ///   name: String
+    /// }
+    /// ```
+    pub fn to_graphql_sdl(&self, options: &SdlExportOptions) -> String {
+        let mut out = String::new();
+
+        if options.shows_descriptions() {
+            if let Some(ref docs) = self.documentation {
+                write_graphql_description(&mut out, docs);
+            }
+        }
+
+        writeln!(out, "type {} {{", self.name).unwrap();
+
+        let mut fields: Vec<(Option<String>, String)> = self
+            .fields
+            .iter()
+            .map(|field| field.to_graphql_sdl(options))
+            .filter(|rendered| !rendered.is_empty())
+            .map(|rendered| {
+                let name = rendered
+                    .lines()
+                    .last()
+                    .and_then(|line| line.split(':').next().map(|name| name.trim().to_owned()));
+
+                let block = rendered
+                    .trim_end()
+                    .lines()
+                    .map(|line| format!("  {line}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                (name, block)
+            })
+            .collect();
+
+        if options.is_sorted() {
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        for (_, field) in fields {
+            writeln!(out, "{field}").unwrap();
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_graphql_sdl_wraps_fields_in_a_type() {
+        let mut model = Model::new("User");
+        model.documentation("A registered user.");
+
+        model.push_field(ModelField::new("id", "Int"));
+
+        let mut field = ModelField::new("name", "String");
+        field.optional();
+        model.push_field(field);
+
+        let expected = "\"\"\"\nA registered user.\n\"\"\"\ntype User {\n  id: Int!\n  name: String\n}\n";
+
+        assert_eq!(expected, model.to_graphql_sdl(&SdlExportOptions::default()));
+    }
+}
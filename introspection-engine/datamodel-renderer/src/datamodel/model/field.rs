@@ -1,12 +1,19 @@
 use crate::{
     datamodel::{
-        attributes::FieldAttribute, model::index_field_input::IndexFieldOptions, DefaultValue, FieldType,
-        IdFieldDefinition, Relation,
+        attributes::FieldAttribute,
+        deprecated::{parse_deprecated_marker, Deprecated},
+        graphql::{graphql_string_escape, graphql_type, rendered_field_parts, write_graphql_description},
+        model::index_field_input::IndexFieldOptions,
+        DefaultValue, FieldType, IdFieldDefinition, Relation, RenameRule, RenderOptions, SdlExportOptions,
     },
     value::{Constant, Documentation, Function, Text},
 };
 use psl::dml;
-use std::{borrow::Cow, collections::HashMap, fmt};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Write as _},
+};
 
 /// A field in a model block.
 #[derive(Debug)]
@@ -23,6 +30,7 @@ pub struct ModelField<'a> {
     relation: Option<Relation<'a>>,
     native_type: Option<FieldAttribute<'a>>,
     ignore: Option<FieldAttribute<'a>>,
+    deprecated: Option<Deprecated<'a>>,
 }
 
 impl<'a> ModelField<'a> {
@@ -51,6 +59,7 @@ impl<'a> ModelField<'a> {
             relation: None,
             native_type: None,
             ignore: None,
+            deprecated: None,
         }
     }
 
@@ -244,6 +253,175 @@ impl<'a> ModelField<'a> {
         self.commented_out = true;
     }
 
+    /// Marks the field as deprecated, with an optional reason. Renders as a
+    /// `@deprecated` tag on its own documentation line, ahead of any
+    /// `documentation` already set on the field.
+    ///
+    /// ```ignore
+    /// model Address {
+    ///   /// @deprecated use `street2` instead
+    ///   street String
+    /// }
+    /// ```
+    pub fn deprecated(&mut self, reason: Option<impl Into<Cow<'a, str>>>) {
+        self.deprecated = Some(Deprecated::new(reason));
+    }
+
+    /// Sets `documentation` from a DML doc string, pulling a leading
+    /// `@deprecated` marker (if any) out into [`deprecated`](Self::deprecated)
+    /// instead of leaving it as plain text, so a schema rendered by this
+    /// crate and re-parsed back into DML round-trips through the same
+    /// structured deprecation.
+    fn apply_documentation(&mut self, docs: &str) {
+        let (deprecated, remainder) = parse_deprecated_marker(docs);
+
+        if let Some(deprecated) = deprecated {
+            self.deprecated = Some(deprecated);
+        }
+
+        if !remainder.is_empty() {
+            self.documentation(remainder);
+        }
+    }
+
+    /// Renders this field as a line of GraphQL SDL, mapping the PSL type to
+    /// the matching GraphQL scalar (or keeping enum, relation and composite
+    /// type names as-is) and turning `documentation` into the field's
+    /// description. Commented-out fields render as an empty string, since
+    /// they have no place in a GraphQL schema.
+    ///
+    /// ```ignore
+    /// """
+    /// When this address was created.
+    /// """
+    /// createdAt: String!
+    /// ```
+    pub fn to_graphql_sdl(&self, options: &SdlExportOptions) -> String {
+        let mut out = String::new();
+
+        if self.commented_out {
+            return out;
+        }
+
+        if options.shows_descriptions() {
+            if let Some(ref docs) = self.documentation {
+                write_graphql_description(&mut out, docs);
+            }
+        }
+
+        let rendered = self.to_string();
+
+        if let Some((name, type_token)) = rendered_field_parts(&rendered) {
+            write!(out, "{name}: {}", graphql_type(type_token)).unwrap();
+
+            if let Some(ref dep) = self.deprecated {
+                match dep.reason() {
+                    Some(reason) => write!(out, " @deprecated(reason: \"{}\")", graphql_string_escape(reason)).unwrap(),
+                    None => out.push_str(" @deprecated"),
+                }
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Converts this field's name to `rule`'s naming convention, filling in
+    /// `map(...)` when the converted name differs from the original. Does
+    /// nothing if the field's name already conforms to the convention, if
+    /// it's already mapped to a real column name (which may have nothing to
+    /// do with the naming convention), or if the field is a relation:
+    /// relation fields have no underlying column of their own, so `@map` on
+    /// them isn't valid PSL (mirrors `from_dml`, which never emits
+    /// `database_name` for a `RelationField`).
+    pub fn apply_rename_rule(&mut self, rule: RenameRule) {
+        if self.relation.is_some() || self.map.is_some() {
+            return;
+        }
+
+        let original = self.name.to_string();
+        let mapped = rule.apply(&original);
+
+        if mapped != original {
+            self.map(mapped);
+        }
+    }
+
+    /// Renders this field the same way as [`fmt::Display`], but honoring
+    /// `options`: attributes can be sorted alphabetically, `documentation`
+    /// can be suppressed, and the field can be dropped entirely instead of
+    /// rendered as a PSL comment when it's commented out. Useful for
+    /// producing a normalized, diffable form regardless of the order
+    /// attributes were set in.
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut out = String::new();
+
+        if self.commented_out && options.strips_commented_out() {
+            return out;
+        }
+
+        if let Some(ref dep) = self.deprecated {
+            out.push_str(&dep.render_doc_lines());
+        }
+
+        if options.shows_documentation() {
+            if let Some(ref docs) = self.documentation {
+                out.push_str(&docs.to_string());
+            }
+        }
+
+        if self.commented_out {
+            out.push_str("// ");
+        }
+
+        write!(out, "{} {}", self.name, self.r#type).unwrap();
+
+        let mut attributes: Vec<(&str, String)> = Vec::new();
+
+        if let Some(ref updated_at) = self.updated_at {
+            attributes.push(("updatedAt", updated_at.to_string()));
+        }
+
+        if let Some(ref unique) = self.unique {
+            attributes.push(("unique", unique.to_string()));
+        }
+
+        if let Some(ref id) = self.id {
+            attributes.push(("id", id.to_string()));
+        }
+
+        if let Some(ref def) = self.default {
+            attributes.push(("default", def.to_string()));
+        }
+
+        if let Some(ref map) = self.map {
+            attributes.push(("map", map.to_string()));
+        }
+
+        if let Some(ref relation) = self.relation {
+            attributes.push(("relation", relation.to_string()));
+        }
+
+        if let Some(ref nt) = self.native_type {
+            attributes.push(("nativeType", nt.to_string()));
+        }
+
+        if let Some(ref ignore) = self.ignore {
+            attributes.push(("ignore", ignore.to_string()));
+        }
+
+        if options.sorts_attributes() {
+            attributes.sort_by(|a, b| a.0.cmp(b.0));
+        }
+
+        for (_, rendered) in attributes {
+            write!(out, " {rendered}").unwrap();
+        }
+
+        out
+    }
+
     /// Generate a model field rendering from the deprecated DML structure.
     ///
     /// Remove when destroying the DML. This API cannot really be
@@ -285,7 +463,7 @@ impl<'a> ModelField<'a> {
                 }
 
                 if let Some(ref docs) = sf.documentation {
-                    field.documentation(docs.clone());
+                    field.apply_documentation(docs);
                 }
 
                 if let Some(dv) = sf.default_value() {
@@ -335,7 +513,7 @@ impl<'a> ModelField<'a> {
                 }
 
                 if let Some(ref docs) = rf.documentation {
-                    field.documentation(docs.clone());
+                    field.apply_documentation(docs);
                 }
 
                 if rf.is_ignored {
@@ -386,7 +564,7 @@ impl<'a> ModelField<'a> {
                 }
 
                 if let Some(ref docs) = cf.documentation {
-                    field.documentation(docs.clone());
+                    field.apply_documentation(docs);
                 }
 
                 if let Some(ref map) = cf.database_name {
@@ -413,48 +591,110 @@ impl<'a> ModelField<'a> {
 
 impl<'a> fmt::Display for ModelField<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(ref docs) = self.documentation {
-            docs.fmt(f)?;
-        }
+        f.write_str(&self.render_with(&RenderOptions::default()))
+    }
+}
 
-        if self.commented_out {
-            f.write_str("// ")?;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        write!(f, "{} {}", self.name, self.r#type)?;
+    #[test]
+    fn to_graphql_sdl_renders_description_and_deprecation() {
+        let mut field = ModelField::new("street2", "String");
+        field.optional();
+        field.documentation("The second address line.");
+        field.deprecated(Some("use `street` instead"));
 
-        if let Some(ref updated_at) = self.updated_at {
-            write!(f, " {updated_at}")?;
-        }
+        let rendered = field.to_graphql_sdl(&SdlExportOptions::default());
 
-        if let Some(ref unique) = self.unique {
-            write!(f, " {unique}")?;
-        }
+        assert_eq!(
+            rendered,
+            "\"\"\"\nThe second address line.\n\"\"\"\nstreet2: String @deprecated(reason: \"use `street` instead\")\n"
+        );
+    }
 
-        if let Some(ref id) = self.id {
-            write!(f, " {id}")?;
-        }
+    #[test]
+    fn to_graphql_sdl_skips_commented_out_fields() {
+        let mut field = ModelField::new("street2", "String");
+        field.commented_out();
 
-        if let Some(ref def) = self.default {
-            write!(f, " {def}")?;
-        }
+        assert_eq!(field.to_graphql_sdl(&SdlExportOptions::default()), "");
+    }
 
-        if let Some(ref map) = self.map {
-            write!(f, " {map}")?;
-        }
+    #[test]
+    fn apply_rename_rule_maps_non_conforming_names() {
+        let mut field = ModelField::new("createdAt", "DateTime");
+        field.apply_rename_rule(RenameRule::SnakeCase);
 
-        if let Some(ref relation) = self.relation {
-            write!(f, " {relation}")?;
-        }
+        assert_eq!(field.to_string(), r#"createdAt DateTime @map("created_at")"#);
+    }
 
-        if let Some(ref nt) = self.native_type {
-            write!(f, " {nt}")?;
-        }
+    #[test]
+    fn apply_rename_rule_skips_relation_fields() {
+        let mut field = ModelField::new("createdBy", "User");
+        field.relation(Relation::new());
+        field.apply_rename_rule(RenameRule::SnakeCase);
 
-        if let Some(ref ignore) = self.ignore {
-            write!(f, " {ignore}")?;
-        }
+        assert!(field.map.is_none());
+    }
+
+    #[test]
+    fn apply_rename_rule_keeps_existing_map() {
+        let mut field = ModelField::new("createdAt", "DateTime");
+        field.map("creation_date");
+        field.apply_rename_rule(RenameRule::SnakeCase);
+
+        assert_eq!(field.to_string(), r#"createdAt DateTime @map("creation_date")"#);
+    }
+
+    #[test]
+    fn render_with_sorts_attributes_and_hides_documentation() {
+        let mut field = ModelField::new("street", "String");
+        field.documentation("The street name.");
+        field.map("Straße");
+        field.updated_at();
+
+        let mut options = RenderOptions::default();
+        options.sort_attributes();
+        options.hide_documentation();
+
+        assert_eq!(
+            field.render_with(&options),
+            r#"street String @map("Straße") @updatedAt"#
+        );
+    }
+
+    #[test]
+    fn render_with_strips_commented_out_fields() {
+        let mut field = ModelField::new("street", "String");
+        field.commented_out();
+
+        let mut options = RenderOptions::default();
+        options.strip_commented_out();
+
+        assert_eq!(field.render_with(&options), "");
+    }
+
+    #[test]
+    fn apply_documentation_round_trips_deprecation() {
+        let mut field = ModelField::new("street2", "String");
+        field.apply_documentation("@deprecated use `street` instead\nThe second address line.");
+
+        assert_eq!(
+            field.to_string(),
+            "/// @deprecated use `street` instead\n/// The second address line.\nstreet2 String"
+        );
+    }
+
+    #[test]
+    fn deprecated_with_multiline_reason_prefixes_every_line() {
+        let mut field = ModelField::new("street2", "String");
+        field.deprecated(Some("use `street` instead\nsee the migration guide"));
 
-        Ok(())
+        assert_eq!(
+            field.to_string(),
+            "/// @deprecated use `street` instead\n/// see the migration guide\nstreet2 String"
+        );
     }
 }
@@ -0,0 +1,116 @@
+/// A naming convention that [`ModelField::apply_rename_rule`](crate::datamodel::ModelField::apply_rename_rule)
+/// and [`CompositeType::apply_rename_rule`](crate::datamodel::CompositeType::apply_rename_rule) can convert a
+/// field name into, filling in `map(...)` whenever the converted name differs from the original.
+///
+/// Modeled on serde_derive's `internals/case.rs` and async-graphql's `RenameRuleExt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    /// `created_at`
+    SnakeCase,
+    /// `createdAt`
+    CamelCase,
+    /// `CreatedAt`
+    PascalCase,
+    /// `CREATED_AT`
+    ScreamingSnakeCase,
+    /// `created-at`
+    KebabCase,
+}
+
+impl RenameRule {
+    /// Converts `name` into this rule's convention. Returns the original
+    /// words rejoined, so an already-conforming name is returned unchanged.
+    pub fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            RenameRule::SnakeCase => words.join("_"),
+            RenameRule::KebabCase => words.join("-"),
+            RenameRule::ScreamingSnakeCase => words
+                .iter()
+                .map(|word| word.to_ascii_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            RenameRule::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| if i == 0 { word.clone() } else { capitalize(word) })
+                .collect(),
+            RenameRule::PascalCase => words.iter().map(|word| capitalize(word)).collect(),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Splits an identifier into its lowercase logical words, breaking on
+/// underscores, hyphens, and lower-to-upper case boundaries. A run of
+/// uppercase letters followed by a lowercase one is treated as a single
+/// boundary, so `HTTPServer` splits as `["http", "server"]` rather than
+/// `["h", "t", "t", "p", "server"]`.
+pub(crate) fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch == '_' || ch == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if ch.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+
+            let is_boundary = prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase()));
+
+            if is_boundary {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(ch.to_ascii_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_camel_case_words() {
+        assert_eq!(split_words("createdAt"), vec!["created", "at"]);
+    }
+
+    #[test]
+    fn splits_acronym_runs() {
+        assert_eq!(split_words("HTTPServer"), vec!["http", "server"]);
+    }
+
+    #[test]
+    fn renders_every_rule() {
+        assert_eq!(RenameRule::SnakeCase.apply("createdAt"), "created_at");
+        assert_eq!(RenameRule::CamelCase.apply("created_at"), "createdAt");
+        assert_eq!(RenameRule::PascalCase.apply("created_at"), "CreatedAt");
+        assert_eq!(RenameRule::ScreamingSnakeCase.apply("createdAt"), "CREATED_AT");
+        assert_eq!(RenameRule::KebabCase.apply("createdAt"), "created-at");
+    }
+}